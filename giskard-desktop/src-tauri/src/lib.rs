@@ -1,231 +1,585 @@
-use std::process::{Command, Child, Stdio};
+use std::collections::VecDeque;
+use std::path::PathBuf;
 use std::sync::Mutex;
-use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
-// Global reference to the Python backend process
-static BACKEND_PROCESS: Mutex<Option<Child>> = Mutex::new(None);
+use serde::{Deserialize, Serialize};
+use tauri::{Emitter, Manager, State};
+use tauri_plugin_shell::process::{CommandChild, CommandEvent};
+use tauri_plugin_shell::ShellExt;
 
+// Global reference to the Python backend sidecar process
+static BACKEND_PROCESS: Mutex<Option<CommandChild>> = Mutex::new(None);
 
-// API Commands
-#[tauri::command]
-async fn api_get_tasks() -> Result<String, String> {
-    println!("🦀 Rust: Getting tasks from API");
-    
-    match std::process::Command::new("curl")
-        .args(["-s", "http://localhost:5001/api/tasks"])
-        .output()
-    {
-        Ok(output) => {
-            if output.status.success() {
-                let response = String::from_utf8_lossy(&output.stdout);
-                println!("✅ Got tasks: {}", response.len());
-                Ok(response.to_string())
-            } else {
-                let error = String::from_utf8_lossy(&output.stderr);
-                Err(format!("API request failed: {}", error))
-            }
+// The startup/health-check task, kept so it can be cancelled on window close.
+static STARTUP_TASK: Mutex<Option<tauri::async_runtime::JoinHandle<()>>> = Mutex::new(None);
+
+// Supervision state for the backend process: how many times it's been
+// restarted after a crash, and whether the current shutdown was requested by
+// the user (so an intentional stop isn't mistaken for a crash and restarted).
+struct Supervisor {
+    restarts: u32,
+    stopping: bool,
+}
+
+static SUPERVISOR: Mutex<Supervisor> = Mutex::new(Supervisor {
+    restarts: 0,
+    stopping: false,
+});
+
+// Name of the bundled backend sidecar binary (resolved through Tauri's resource
+// resolver, e.g. a PyInstaller-frozen `giskard-backend`). Declared under
+// `tauri.bundle.externalBin` and shipped inside the app bundle so packaged
+// builds don't depend on a system `python3` or the source tree being present.
+const BACKEND_SIDECAR: &str = "giskard-backend";
+
+// How many recent backend log lines to retain for window backfill.
+const BACKEND_LOG_CAPACITY: usize = 500;
+
+// A single line of backend output, emitted to the webview as a `backend-log`
+// event and retained in the ring buffer.
+#[derive(Clone, Serialize)]
+struct BackendLogLine {
+    // "stdout" or "stderr"
+    stream: &'static str,
+    // unix epoch milliseconds the line was read
+    timestamp: u64,
+    text: String,
+}
+
+// Bounded in-memory ring buffer of the most recent backend log lines so a
+// freshly opened window can backfill output it missed via `get_backend_logs`.
+struct BackendLogs(Mutex<VecDeque<BackendLogLine>>);
+
+impl BackendLogs {
+    fn new() -> Self {
+        BackendLogs(Mutex::new(VecDeque::with_capacity(BACKEND_LOG_CAPACITY)))
+    }
+
+    fn push(&self, line: BackendLogLine) {
+        let mut buf = self.0.lock().unwrap();
+        if buf.len() == BACKEND_LOG_CAPACITY {
+            buf.pop_front();
         }
-        Err(e) => Err(format!("Failed to call API: {}", e)),
+        buf.push_back(line);
+    }
+
+    fn snapshot(&self) -> Vec<BackendLogLine> {
+        self.0.lock().unwrap().iter().cloned().collect()
     }
 }
 
-#[tauri::command]
-async fn api_create_task(title: String, description: String, project: Option<String>, categories: Option<String>) -> Result<String, String> {
-    println!("🦀 Rust: Creating task - title: {}, desc: {}, project: {:?}, categories: {:?}", title, description, project, categories);
-    
-    let mut json_body = format!(r#"{{"title": "{}", "description": "{}""#, 
-                               title.replace("\"", "\\\""), 
-                               description.replace("\"", "\\\""));
-    
-    if let Some(proj) = project {
-        json_body.push_str(&format!(r#", "project": "{}""#, proj.replace("\"", "\\\"")));
-    }
-    
-    if let Some(cats) = categories {
-        // Parse categories from comma-separated string to array
-        let categories_array: Vec<&str> = cats.split(',').map(|s| s.trim()).collect();
-        let categories_json = serde_json::to_string(&categories_array).unwrap_or_else(|_| "[]".to_string());
-        json_body.push_str(&format!(r#", "categories": {}"#, categories_json));
-    } else {
-        json_body.push_str(r#", "categories": []"#);
-    }
-    
-    json_body.push('}');
-    
-    match std::process::Command::new("curl")
-        .args([
-            "-X", "POST",
-            "http://localhost:5001/api/tasks",
-            "-H", "Content-Type: application/json",
-            "-d", &json_body,
-            "-s"
-        ])
-        .output()
-    {
-        Ok(output) => {
-            if output.status.success() {
-                let response = String::from_utf8_lossy(&output.stdout);
-                println!("✅ Task created: {}", response);
-                Ok(response.to_string())
-            } else {
-                let error = String::from_utf8_lossy(&output.stderr);
-                Err(format!("Create task failed: {}", error))
-            }
+fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+// Managed HTTP client used by every command to talk to the Python backend.
+// Holds a single reqwest::Client for connection pooling; the base URL is built
+// per-request from the persisted BackendConfig so the individual commands never
+// hand-build requests or shell out to `curl`.
+struct ApiClient {
+    client: reqwest::Client,
+}
+
+impl ApiClient {
+    fn new() -> Self {
+        ApiClient {
+            client: reqwest::Client::new(),
         }
-        Err(e) => Err(format!("Failed to create task: {}", e)),
     }
 }
 
-#[tauri::command]
-async fn api_update_task_status(task_id: u32, status: String) -> Result<String, String> {
-    println!("🦀 Rust: Updating task status - task_id: {}, status: {}", task_id, status);
-    
-    let json_body = format!(r#"{{"status": "{}"}}"#, status.replace("\"", "\\\""));
-    
-    match std::process::Command::new("curl")
-        .args([
-            "-X", "PATCH",
-            &format!("http://localhost:5001/api/tasks/{}/status", task_id),
-            "-H", "Content-Type: application/json",
-            "-d", &json_body,
-            "-s"
-        ])
-        .output()
-    {
-        Ok(output) => {
-            if output.status.success() {
-                let response = String::from_utf8_lossy(&output.stdout);
-                println!("✅ Task status updated: {}", response);
-                Ok(response.to_string())
-            } else {
-                let error = String::from_utf8_lossy(&output.stderr);
-                Err(format!("Update task status failed: {}", error))
-            }
+// Persisted backend settings, loaded from `backend.toml` in the app config dir
+// on startup (written with defaults if absent) and held in managed state. Lets
+// users point the app at a remote backend without recompiling. The backend
+// binary itself is always the bundled sidecar (see `BACKEND_SIDECAR`), so
+// there is no interpreter path to configure here.
+#[derive(Clone, Serialize, Deserialize)]
+#[serde(default)]
+struct BackendConfig {
+    host: String,
+    port: u16,
+    auto_start: bool,
+    // How many times to restart the backend after an unexpected crash before
+    // giving up.
+    max_restarts: u32,
+}
+
+impl Default for BackendConfig {
+    fn default() -> Self {
+        BackendConfig {
+            host: "localhost".to_string(),
+            port: 5001,
+            auto_start: true,
+            max_restarts: 3,
         }
-        Err(e) => Err(format!("Failed to update task status: {}", e)),
     }
 }
 
-#[tauri::command]
-fn check_backend_status() -> Result<String, String> {
-    // Try to make a request to the Python backend using curl
-    match std::process::Command::new("curl")
-        .args(["-s", "-f", "http://localhost:5001/api/tasks"])
-        .output()
-    {
-        Ok(output) => {
-            if output.status.success() {
-                Ok("Backend is running".to_string())
-            } else {
-                Err("Backend not responding".to_string())
+impl BackendConfig {
+    fn base_url(&self) -> String {
+        format!("http://{}:{}", self.host, self.port)
+    }
+}
+
+const BACKEND_CONFIG_FILE: &str = "backend.toml";
+
+fn config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_config_dir()
+        .map(|dir| dir.join(BACKEND_CONFIG_FILE))
+        .map_err(|e| format!("Could not resolve config dir: {}", e))
+}
+
+// Load the config, writing defaults to disk the first time it's missing.
+fn load_backend_config(app: &tauri::AppHandle) -> BackendConfig {
+    let path = match config_path(app) {
+        Ok(path) => path,
+        Err(err) => {
+            eprintln!("⚠️  {}; using default backend config", err);
+            return BackendConfig::default();
+        }
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(text) => toml::from_str(&text).unwrap_or_else(|e| {
+            eprintln!("⚠️  Malformed backend config ({}); using defaults", e);
+            BackendConfig::default()
+        }),
+        Err(_) => {
+            let config = BackendConfig::default();
+            if let Err(err) = save_backend_config(app, &config) {
+                eprintln!("⚠️  Could not persist default backend config: {}", err);
             }
+            config
         }
-        Err(_) => Err("Could not check backend status".to_string()),
     }
 }
 
+fn save_backend_config(app: &tauri::AppHandle, config: &BackendConfig) -> Result<(), String> {
+    let path = config_path(app)?;
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| format!("Could not create config dir: {}", e))?;
+    }
+    let text = toml::to_string_pretty(config)
+        .map_err(|e| format!("Could not serialize backend config: {}", e))?;
+    std::fs::write(&path, text).map_err(|e| format!("Could not write backend config: {}", e))
+}
+
+// A single task as returned by the backend. Unknown fields are preserved via
+// `extra` so the frontend keeps receiving everything the backend sends.
+#[derive(Serialize, Deserialize)]
+struct Task {
+    id: u32,
+    title: String,
+    description: String,
+    status: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    #[serde(default)]
+    categories: Vec<String>,
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+// Body for POST /api/tasks — categories is serialized as a real JSON array.
+#[derive(Serialize)]
+struct CreateTaskRequest {
+    title: String,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    project: Option<String>,
+    categories: Vec<String>,
+}
+
+// Body for PATCH /api/tasks/{id}/status.
+#[derive(Serialize)]
+struct UpdateTaskStatusRequest {
+    status: String,
+}
+
+// API Commands
 #[tauri::command]
-fn start_python_backend() -> Result<String, String> {
-    println!("🚀 Starting Python backend...");
-    
-    // Get the parent directory (where your Python app.py is located)
-    let exe_path = std::env::current_exe()
-        .map_err(|e| format!("Failed to get executable path: {}", e))?;
-    
-    let parent_dir = exe_path.parent()
-        .and_then(|p| p.parent())
-        .and_then(|p| p.parent())
-        .and_then(|p| p.parent())
-        .ok_or("Could not determine parent directory")?;
-    
-    println!("🔍 Looking for Python backend in: {:?}", parent_dir);
-    
-    // Try different possible locations for the Python backend
-    let possible_paths = vec![
-        parent_dir.join("app.py"),
-        parent_dir.parent().unwrap_or(parent_dir).join("app.py"),
-        std::path::PathBuf::from("../app.py"),
-        std::path::PathBuf::from("../../app.py"),
-    ];
-    
-    let mut backend_path = None;
-    for path in possible_paths {
-        if path.exists() {
-            backend_path = Some(path);
-            break;
-        }
+async fn api_get_tasks(
+    api: State<'_, ApiClient>,
+    config: State<'_, Mutex<BackendConfig>>,
+) -> Result<Vec<Task>, String> {
+    println!("🦀 Rust: Getting tasks from API");
+
+    let base = config.lock().unwrap().base_url();
+    let tasks = api
+        .client
+        .get(format!("{}/api/tasks", base))
+        .send()
+        .await
+        .map_err(|e| format!("API request failed: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("API request failed: {}", e))?
+        .json::<Vec<Task>>()
+        .await
+        .map_err(|e| format!("Failed to parse tasks: {}", e))?;
+
+    println!("✅ Got tasks: {}", tasks.len());
+    Ok(tasks)
+}
+
+#[tauri::command]
+async fn api_create_task(
+    api: State<'_, ApiClient>,
+    config: State<'_, Mutex<BackendConfig>>,
+    title: String,
+    description: String,
+    project: Option<String>,
+    categories: Option<String>,
+) -> Result<Task, String> {
+    println!("🦀 Rust: Creating task - title: {}, desc: {}, project: {:?}, categories: {:?}", title, description, project, categories);
+
+    // Parse the comma-separated categories into a real list; an empty/None
+    // value becomes an empty array.
+    let categories = categories
+        .map(|cats| {
+            cats.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<String>>()
+        })
+        .unwrap_or_default();
+
+    let body = CreateTaskRequest {
+        title,
+        description,
+        project,
+        categories,
+    };
+
+    let base = config.lock().unwrap().base_url();
+    let task = api
+        .client
+        .post(format!("{}/api/tasks", base))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Create task failed: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("Create task failed: {}", e))?
+        .json::<Task>()
+        .await
+        .map_err(|e| format!("Failed to parse created task: {}", e))?;
+
+    println!("✅ Task created: {}", task.id);
+    Ok(task)
+}
+
+#[tauri::command]
+async fn api_update_task_status(
+    api: State<'_, ApiClient>,
+    config: State<'_, Mutex<BackendConfig>>,
+    task_id: u32,
+    status: String,
+) -> Result<Task, String> {
+    println!("🦀 Rust: Updating task status - task_id: {}, status: {}", task_id, status);
+
+    let body = UpdateTaskStatusRequest { status };
+
+    let base = config.lock().unwrap().base_url();
+    let task = api
+        .client
+        .patch(format!("{}/api/tasks/{}/status", base, task_id))
+        .json(&body)
+        .send()
+        .await
+        .map_err(|e| format!("Update task status failed: {}", e))?
+        .error_for_status()
+        .map_err(|e| format!("Update task status failed: {}", e))?
+        .json::<Task>()
+        .await
+        .map_err(|e| format!("Failed to parse updated task: {}", e))?;
+
+    println!("✅ Task status updated: {}", task.id);
+    Ok(task)
+}
+
+#[tauri::command]
+async fn check_backend_status(
+    api: State<'_, ApiClient>,
+    config: State<'_, Mutex<BackendConfig>>,
+) -> Result<String, String> {
+    // Probe the backend with a plain GET; any non-success status or transport
+    // error means the backend isn't ready yet.
+    let base = config.lock().unwrap().base_url();
+    match api.client.get(format!("{}/api/tasks", base)).send().await {
+        Ok(response) if response.status().is_success() => Ok("Backend is running".to_string()),
+        Ok(_) => Err("Backend not responding".to_string()),
+        Err(_) => Err("Could not check backend status".to_string()),
     }
-    
-    let backend_path = backend_path.ok_or("Could not find app.py. Please ensure it's in the parent directory.")?;
-    
-    println!("✅ Found Python backend at: {:?}", backend_path);
-    
-    // Start the Python backend process with correct working directory
-    let backend_dir = backend_path.parent().unwrap_or_else(|| std::path::Path::new("."));
-    let child = Command::new("python3")
-        .arg(backend_path.file_name().unwrap())
-        .current_dir(backend_dir)
-        .stdout(Stdio::null())
-        .stderr(Stdio::null())
+}
+
+// Spawn the bundled backend sidecar. Resolution failures ("binary not found"
+// — the sidecar wasn't bundled for this target) are surfaced distinctly from
+// spawn failures (the binary exists but couldn't be launched) so packaged
+// builds can report the right thing.
+fn spawn_backend(app: &tauri::AppHandle) -> Result<(), String> {
+    println!("🚀 Starting Python backend...");
+
+    let sidecar = app
+        .shell()
+        .sidecar(BACKEND_SIDECAR)
+        .map_err(|e| format!("Backend binary not found: {}", e))?;
+
+    let (mut rx, child) = sidecar
         .spawn()
         .map_err(|e| format!("Failed to start Python backend: {}", e))?;
-    
+
     // Store the process handle globally so we can kill it later
     *BACKEND_PROCESS.lock().unwrap() = Some(child);
-    
+
+    // Drain the sidecar's stdout/stderr line-by-line on the async runtime,
+    // emitting each line to the webview and stashing it in the ring buffer.
+    // The same loop observes process termination and drives supervision.
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            match event {
+                CommandEvent::Stdout(bytes) => emit_log_line(&app, "stdout", bytes),
+                CommandEvent::Stderr(bytes) => emit_log_line(&app, "stderr", bytes),
+                CommandEvent::Terminated(payload) => {
+                    handle_backend_exit(&app, payload.code);
+                    break;
+                }
+                _ => {}
+            }
+        }
+    });
+
     println!("🎉 Python backend started successfully!");
+    Ok(())
+}
+
+// Push a line of backend output to the ring buffer and emit it to the webview.
+fn emit_log_line(app: &tauri::AppHandle, stream: &'static str, bytes: Vec<u8>) {
+    let line = BackendLogLine {
+        stream,
+        timestamp: now_millis(),
+        text: String::from_utf8_lossy(&bytes).trim_end().to_string(),
+    };
+    app.state::<BackendLogs>().push(line.clone());
+    let _ = app.emit("backend-log", line);
+}
+
+// React to the backend process exiting. An exit that follows a user-requested
+// stop is expected and resets supervision state; any other exit is treated as
+// a crash — a `backend-crashed` event (payload: the exit code) is emitted and
+// the backend is restarted, with backoff, up to the configured retry limit.
+fn handle_backend_exit(app: &tauri::AppHandle, code: Option<i32>) {
+    let mut sup = SUPERVISOR.lock().unwrap();
+
+    if sup.stopping {
+        sup.stopping = false;
+        sup.restarts = 0;
+        println!("🛑 Backend stopped");
+        return;
+    }
+
+    eprintln!("💥 Backend exited unexpectedly (code {:?})", code);
+    let _ = app.emit("backend-crashed", code);
+
+    let max_restarts = app.state::<Mutex<BackendConfig>>().lock().unwrap().max_restarts;
+    if sup.restarts >= max_restarts {
+        eprintln!("❌ Backend crashed {} times; giving up", sup.restarts + 1);
+        let _ = app.emit("backend-failed", "backend crashed too many times".to_string());
+        return;
+    }
+
+    sup.restarts += 1;
+    let attempt = sup.restarts;
+    drop(sup);
+
+    // Restart on the async runtime with a linear backoff, reusing the same
+    // spawn path as startup. Only once the restarted backend actually passes
+    // a health probe is it considered recovered, resetting the crash counter
+    // — otherwise unrelated crashes hours apart would keep accumulating
+    // against `max_restarts` even though each one fully recovered.
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        tokio::time::sleep(Duration::from_secs(attempt.min(5) as u64)).await;
+        println!("🔁 Restarting backend (attempt {})", attempt);
+        if let Err(err) = spawn_backend(&app) {
+            eprintln!("❌ Restart failed: {}", err);
+            let _ = app.emit("backend-failed", err);
+            return;
+        }
+
+        match wait_for_backend_ready(&app).await {
+            Ok(()) => {
+                println!("✅ Backend recovered after restart");
+                SUPERVISOR.lock().unwrap().restarts = 0;
+                let _ = app.emit("backend-ready", ());
+            }
+            Err(err) => {
+                eprintln!("❌ Backend did not become healthy after restart: {}", err);
+                let _ = app.emit("backend-failed", err);
+            }
+        }
+    });
+}
+
+#[tauri::command]
+fn get_backend_logs(logs: State<'_, BackendLogs>) -> Vec<BackendLogLine> {
+    logs.snapshot()
+}
+
+#[tauri::command]
+fn start_python_backend(app: tauri::AppHandle) -> Result<String, String> {
+    spawn_backend(&app)?;
     Ok("Python backend started".to_string())
 }
 
+#[tauri::command]
+fn get_backend_config(config: State<'_, Mutex<BackendConfig>>) -> BackendConfig {
+    config.lock().unwrap().clone()
+}
+
+#[tauri::command]
+fn set_backend_config(
+    app: tauri::AppHandle,
+    config: State<'_, Mutex<BackendConfig>>,
+    new_config: BackendConfig,
+) -> Result<(), String> {
+    save_backend_config(&app, &new_config)?;
+    *config.lock().unwrap() = new_config;
+    Ok(())
+}
+
+#[tauri::command]
+fn stop_backend() -> Result<(), String> {
+    // Mark the shutdown as intentional so the supervisor doesn't restart it.
+    SUPERVISOR.lock().unwrap().stopping = true;
+    if let Some(child) = BACKEND_PROCESS.lock().unwrap().take() {
+        child.kill().map_err(|e| format!("Failed to stop backend: {}", e))?;
+    }
+    Ok(())
+}
+
+#[tauri::command]
+fn restart_backend(app: tauri::AppHandle) -> Result<String, String> {
+    {
+        let mut sup = SUPERVISOR.lock().unwrap();
+        sup.stopping = true;
+        sup.restarts = 0;
+    }
+    if let Some(child) = BACKEND_PROCESS.lock().unwrap().take() {
+        let _ = child.kill();
+    }
+    spawn_backend(&app)?;
+    Ok("Backend restarted".to_string())
+}
+
+// Poll the backend's health with exponential backoff until it responds or
+// the attempt budget is exhausted. Shared by the initial startup probe and
+// the post-restart probe so both agree on what "recovered" means.
+async fn wait_for_backend_ready(app: &tauri::AppHandle) -> Result<(), String> {
+    const MAX_ATTEMPTS: u32 = 10;
+    let mut delay = Duration::from_millis(500);
+    let mut last_err = String::new();
+    for attempt in 1..=MAX_ATTEMPTS {
+        tokio::time::sleep(delay).await;
+        match check_backend_status(app.state::<ApiClient>(), app.state::<Mutex<BackendConfig>>()).await {
+            Ok(msg) => {
+                println!("✅ Backend ready after {} attempt(s): {}", attempt, msg);
+                return Ok(());
+            }
+            Err(err) => {
+                println!("⏳ Waiting for backend... (attempt {})", attempt);
+                last_err = err;
+            }
+        }
+        // Exponential backoff, capped so retries don't stretch out indefinitely.
+        delay = (delay * 2).min(Duration::from_secs(5));
+    }
+    Err(last_err)
+}
+
+// Start the backend and poll its health, emitting lifecycle events the
+// frontend can react to: `backend-starting` once the spawn is attempted,
+// `backend-ready` when the health probe first succeeds, and `backend-failed`
+// (payload: the error string) if the spawn fails or the backend never comes
+// up. Runs on the async runtime and is cancellable.
+async fn run_backend_startup(app: tauri::AppHandle) {
+    let _ = app.emit("backend-starting", ());
+
+    if let Err(err) = spawn_backend(&app) {
+        eprintln!("❌ Failed to start backend: {}", err);
+        let _ = app.emit("backend-failed", err);
+        return;
+    }
+
+    match wait_for_backend_ready(&app).await {
+        Ok(()) => {
+            SUPERVISOR.lock().unwrap().restarts = 0;
+            let _ = app.emit("backend-ready", ());
+        }
+        Err(err) => {
+            eprintln!("❌ Backend failed to start: {}", err);
+            let _ = app.emit("backend-failed", err);
+        }
+    }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_http::init())
+        .plugin(tauri_plugin_shell::init())
+        .manage(ApiClient::new())
+        .manage(BackendLogs::new())
         .invoke_handler(tauri::generate_handler![
             check_backend_status,
             start_python_backend,
+            get_backend_logs,
+            get_backend_config,
+            set_backend_config,
+            stop_backend,
+            restart_backend,
             api_get_tasks,
             api_create_task,
             api_update_task_status
         ])
-        .setup(|_app| {
-            // Start the Python backend when the app launches in a separate thread
-            thread::spawn(move || {
-                // Wait a moment for the app to fully initialize
-                thread::sleep(std::time::Duration::from_millis(500));
-                
-                match start_python_backend() {
-                    Ok(msg) => println!("✅ {}", msg),
-                    Err(err) => eprintln!("❌ Failed to start backend: {}", err),
-                }
-                
-                // Wait longer and retry backend health check multiple times
-                for i in 1..=10 {
-                    thread::sleep(std::time::Duration::from_millis(1000));
-                    match check_backend_status() {
-                        Ok(msg) => {
-                            println!("✅ Backend ready after {}s: {}", i, msg);
-                            break;
-                        },
-                        Err(err) => {
-                            if i == 10 {
-                                eprintln!("❌ Backend failed to start after 10s: {}", err);
-                            } else {
-                                println!("⏳ Waiting for backend... ({}s)", i);
-                            }
-                        }
-                    }
-                }
-            });
-            
+        .setup(|app| {
+            // Load persisted backend settings (writing defaults on first run)
+            // and hold them in managed state before anything reads them.
+            let handle = app.handle().clone();
+            let config = load_backend_config(&handle);
+            let auto_start = config.auto_start;
+            app.manage(Mutex::new(config));
+
+            // Start the Python backend and health-check it on the async runtime
+            // unless the user has opted out of auto-start.
+            if auto_start {
+                let task = tauri::async_runtime::spawn(run_backend_startup(handle));
+                *STARTUP_TASK.lock().unwrap() = Some(task);
+            }
+
             Ok(())
         })
         .on_window_event(|_window, event| {
             if let tauri::WindowEvent::CloseRequested { .. } = event {
+                // Cancel an in-flight startup/health-check before tearing down.
+                if let Some(task) = STARTUP_TASK.lock().unwrap().take() {
+                    task.abort();
+                }
+
+                // Mark the teardown as intentional so the supervisor doesn't
+                // try to restart the backend as it exits.
+                SUPERVISOR.lock().unwrap().stopping = true;
+
                 // Clean up the Python backend process when the window closes
                 if let Ok(mut process) = BACKEND_PROCESS.lock() {
-                    if let Some(mut child) = process.take() {
+                    if let Some(child) = process.take() {
                         let _ = child.kill();
                         println!("🛑 Python backend process terminated");
                     }